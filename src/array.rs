@@ -1,167 +1,84 @@
-//! Untyped safe interface for NumPy ndarray
+//! Statically-typed safe interface for NumPy ndarray
 
 use ndarray::*;
 use npyffi;
 use pyo3::*;
 
+use std::marker::PhantomData;
+use std::mem::size_of;
 use std::os::raw::c_void;
 use std::ptr::null_mut;
 
-use super::error::ArrayCastError;
+use super::borrow;
+use super::error::{ArrayCastError, IndexError, NotContiguousError, ReshapeError};
+use super::slice_container;
 use super::*;
 
-/// Untyped safe interface for NumPy ndarray.
-pub struct PyArray(PyObject);
-pyobject_native_type!(PyArray, *npyffi::PyArray_Type_Ptr, npyffi::PyArray_Check);
+/// A NumPy array whose element type `T` and dimensionality `D` are tracked
+/// in the type system.
+///
+/// The fully dynamic form (unknown dimensionality) is `PyArray<T, IxDyn>`,
+/// aliased as [`PyArrayDyn`](type.PyArrayDyn.html). Use
+/// [`cast`](#method.cast) to move from the dynamic form to a concrete `D`,
+/// and [`into_dyn`](#method.into_dyn) to erase it again.
+pub struct PyArray<T, D>(PyObject, PhantomData<(T, D)>);
 
-impl IntoPyObject for PyArray {
-    fn into_object(self, _py: Python) -> PyObject {
-        self.0
-    }
-}
+/// A `PyArray` whose dimensionality is only known at runtime.
+pub type PyArrayDyn<T> = PyArray<T, IxDyn>;
 
-impl PyArray {
-    /// Get raw pointer for PyArrayObject
-    pub fn as_array_ptr(&self) -> *mut npyffi::PyArrayObject {
-        self.as_ptr() as _
-    }
-
-    /// Construct one-dimension PyArray from boxed slice.
-    ///
-    /// # Example
-    /// ```
-    /// # extern crate pyo3; extern crate numpy; fn main() {
-    /// use numpy::{PyArray, PyArrayModule};
-    /// let gil = pyo3::Python::acquire_gil();
-    /// let np = PyArrayModule::import(gil.python()).unwrap();
-    /// let slice = vec![1, 2, 3, 4, 5].into_boxed_slice();
-    /// let pyarray = PyArray::from_boxed_slice::<u32>(gil.python(), &np, slice);
-    /// assert_eq!(pyarray.as_slice::<u32>().unwrap(), &[1, 2, 3, 4, 5]);
-    /// # }
-    /// ```
-    pub fn from_boxed_slice<T: TypeNum>(py: Python, np: &PyArrayModule, v: Box<[T]>) -> PyArray {
-        IntoPyArray::into_pyarray(v, py, np)
+impl<T, D> ::std::ops::Deref for PyArray<T, D> {
+    type Target = PyObject;
+    fn deref(&self) -> &PyObject {
+        &self.0
     }
+}
 
-    /// Construct one-dimension PyArray from Vec.
-    ///
-    /// # Example
-    /// ```
-    /// # extern crate pyo3; extern crate numpy; fn main() {
-    /// use numpy::{PyArray, PyArrayModule};
-    /// let gil = pyo3::Python::acquire_gil();
-    /// let np = PyArrayModule::import(gil.python()).unwrap();
-    /// let pyarray = PyArray::from_vec::<u32>(gil.python(), &np, vec![1, 2, 3, 4, 5]);
-    /// assert_eq!(pyarray.as_slice::<u32>().unwrap(), &[1, 2, 3, 4, 5]);
-    /// # }
-    /// ```
-    pub fn from_vec<T: TypeNum>(py: Python, np: &PyArrayModule, v: Vec<T>) -> PyArray {
-        IntoPyArray::into_pyarray(v, py, np)
+impl<T, D> IntoPyObject for PyArray<T, D> {
+    fn into_object(self, _py: Python) -> PyObject {
+        self.0
     }
+}
 
-    /// Construct a two-dimension PyArray from `Vec<Vec<T>>`.
-    ///
-    /// This function checks all dimension of inner vec, and if there's any vec
-    /// where its dimension differs from others, it returns `ArrayCastError`.
-    ///
-    /// # Example
-    /// ```
-    /// # extern crate pyo3; extern crate numpy; #[macro_use] extern crate ndarray; fn main() {
-    /// use numpy::{PyArray, PyArrayModule};
-    /// let gil = pyo3::Python::acquire_gil();
-    /// let np = PyArrayModule::import(gil.python()).unwrap();
-    /// let vec2 = vec![vec![1, 2, 3]; 2];
-    /// let pyarray = PyArray::from_vec2::<u32>(gil.python(), &np, &vec2).unwrap();
-    /// assert_eq!(pyarray.as_array::<u32>().unwrap(), array![[1, 2, 3], [1, 2, 3]].into_dyn());
-    /// assert!(PyArray::from_vec2::<u32>(gil.python(), &np, &vec![vec![1], vec![2, 3]]).is_err());
-    /// # }
-    /// ```
-    pub fn from_vec2<T: TypeNum>(
-        py: Python,
-        np: &PyArrayModule,
-        v: &Vec<Vec<T>>,
-    ) -> Result<PyArray, ArrayCastError> {
-        let last_len = v.last().map_or(0, |v| v.len());
-        if v.iter().any(|v| v.len() != last_len) {
-            return Err(ArrayCastError::FromVec);
-        }
-        let dims = [v.len(), last_len];
-        let flattend: Vec<_> = v.iter().cloned().flatten().collect();
+// `PyArray<T, D>` can't use `pyobject_native_type!` like the old untyped
+// `PyArray` did, since that macro ties a Rust type to a single, fixed
+// Python type object and doesn't know about `T`/`D`. So `#[pyfunction]`
+// extraction is done by hand here: reject anything that isn't backed by a
+// `PyArrayObject` via `PyArray_Check` before the transmute below, then
+// check dtype and ndim, the same validation `as_array`/`cast` do.
+impl<'a, T: TypeNum, D: Dimension> FromPyObject<'a> for &'a PyArray<T, D> {
+    fn extract(obj: &'a PyObject) -> PyResult<Self> {
         unsafe {
-            let data = convert::into_raw(flattend);
-            Ok(PyArray::new_::<T>(py, np, &dims, null_mut(), data))
-        }
-    }
-
-    /// Construct a three-dimension PyArray from `Vec<Vec<Vec<T>>>`.
-    ///
-    /// This function checks all dimension of inner vec, and if there's any vec
-    /// where its dimension differs from others, it returns `ArrayCastError`.
-    ///
-    /// # Example
-    /// ```
-    /// # extern crate pyo3; extern crate numpy; #[macro_use] extern crate ndarray; fn main() {
-    /// use numpy::{PyArray, PyArrayModule};
-    /// let gil = pyo3::Python::acquire_gil();
-    /// let np = PyArrayModule::import(gil.python()).unwrap();
-    /// let vec2 = vec![vec![vec![1, 2]; 2]; 2];
-    /// let pyarray = PyArray::from_vec3::<u32>(gil.python(), &np, &vec2).unwrap();
-    /// assert_eq!(
-    ///     pyarray.as_array::<u32>().unwrap(),
-    ///     array![[[1, 2], [1, 2]], [[1, 2], [1, 2]]].into_dyn()
-    /// );
-    /// assert!(PyArray::from_vec3::<u32>(gil.python(), &np, &vec![vec![vec![1], vec![]]]).is_err());
-    /// # }
-    /// ```
-    pub fn from_vec3<T: TypeNum>(
-        py: Python,
-        np: &PyArrayModule,
-        v: &Vec<Vec<Vec<T>>>,
-    ) -> Result<PyArray, ArrayCastError> {
-        let dim2 = v.last().map_or(0, |v| v.len());
-        if v.iter().any(|v| v.len() != dim2) {
-            return Err(ArrayCastError::FromVec);
+            if npyffi::PyArray_Check(obj.as_ptr()) == 0 {
+                return Err(PyErr::new::<pyo3::exc::TypeError, _>(
+                    "argument must be a numpy.ndarray",
+                ));
+            }
         }
-        let dim3 = v.last().map_or(0, |v| v.last().map_or(0, |v| v.len()));
-        if v.iter().any(|v| v.iter().any(|v| v.len() != dim3)) {
-            return Err(ArrayCastError::FromVec);
-        }
-        let dims = [v.len(), dim2, dim3];
-        let flattend: Vec<_> = v.iter().flat_map(|v| v.iter().cloned().flatten()).collect();
-        unsafe {
-            let data = convert::into_raw(flattend);
-            Ok(PyArray::new_::<T>(py, np, &dims, null_mut(), data))
+        let array = unsafe { &*(obj as *const PyObject as *const PyArray<T, D>) };
+        array.type_check()?;
+        if let Some(n) = D::NDIM {
+            if n != array.ndim() {
+                return Err(ArrayCastError::to_dim(n, array.ndim()).into());
+            }
         }
+        Ok(array)
     }
+}
 
-    /// Construct PyArray from ndarray::Array.
-    ///
-    /// # Example
-    /// ```
-    /// # extern crate pyo3; extern crate numpy; #[macro_use] extern crate ndarray; fn main() {
-    /// use numpy::{PyArray, PyArrayModule};
-    /// let gil = pyo3::Python::acquire_gil();
-    /// let np = PyArrayModule::import(gil.python()).unwrap();
-    /// let pyarray = PyArray::from_ndarray::<u32, _>(gil.python(), &np, array![[1, 2], [3, 4]]);
-    /// assert_eq!(pyarray.as_array::<u32>().unwrap(), array![[1, 2], [3, 4]].into_dyn());
-    /// # }
-    /// ```
-    pub fn from_ndarray<A, D>(py: Python, np: &PyArrayModule, arr: Array<A, D>) -> PyArray
-    where
-        A: TypeNum,
-        D: Dimension,
-    {
-        IntoPyArray::into_pyarray(arr, py, np)
+impl<T, D> PyArray<T, D> {
+    /// Get raw pointer for PyArrayObject
+    pub fn as_array_ptr(&self) -> *mut npyffi::PyArrayObject {
+        self.0.as_ptr() as _
     }
 
     pub unsafe fn from_owned_ptr(py: Python, ptr: *mut pyo3::ffi::PyObject) -> Self {
         let obj = PyObject::from_owned_ptr(py, ptr);
-        PyArray(obj)
+        PyArray(obj, PhantomData)
     }
 
     pub unsafe fn from_borrowed_ptr(py: Python, ptr: *mut pyo3::ffi::PyObject) -> Self {
         let obj = PyObject::from_borrowed_ptr(py, ptr);
-        PyArray(obj)
+        PyArray(obj, PhantomData)
     }
 
     /// Returns the number of dimensions in the array.
@@ -171,10 +88,10 @@ impl PyArray {
     /// # Example
     /// ```
     /// # extern crate pyo3; extern crate numpy; fn main() {
-    /// use numpy::{PyArray, PyArrayModule};
+    /// use numpy::{Ix3, PyArray, PyArrayModule};
     /// let gil = pyo3::Python::acquire_gil();
     /// let np = PyArrayModule::import(gil.python()).unwrap();
-    /// let arr = PyArray::new::<f64>(gil.python(), &np, &[4, 5, 6]);
+    /// let arr = PyArray::<f64, Ix3>::new(gil.python(), &np, &[4, 5, 6]);
     /// assert_eq!(arr.ndim(), 3);
     /// # }
     /// ```
@@ -199,13 +116,14 @@ impl PyArray {
     /// Returns a slice which contains dimmensions of the array.
     ///
     /// Same as [numpy.ndarray.shape](https://docs.scipy.org/doc/numpy/reference/generated/numpy.ndarray.shape.html)
+    ///
     /// # Example
     /// ```
     /// # extern crate pyo3; extern crate numpy; fn main() {
-    /// use numpy::{PyArray, PyArrayModule};
+    /// use numpy::{Ix3, PyArray, PyArrayModule};
     /// let gil = pyo3::Python::acquire_gil();
     /// let np = PyArrayModule::import(gil.python()).unwrap();
-    /// let arr = PyArray::new::<f64>(gil.python(), &np, &[4, 5, 6]);
+    /// let arr = PyArray::<f64, Ix3>::new(gil.python(), &np, &[4, 5, 6]);
     /// assert_eq!(arr.shape(), &[4, 5, 6]);
     /// # }
     /// ```
@@ -222,13 +140,14 @@ impl PyArray {
     /// Returns a slice which contains how many bytes you need to jump to the next row.
     ///
     /// Same as [numpy.ndarray.strides](https://docs.scipy.org/doc/numpy/reference/generated/numpy.ndarray.strides.html)
+    ///
     /// # Example
     /// ```
     /// # extern crate pyo3; extern crate numpy; fn main() {
-    /// use numpy::{PyArray, PyArrayModule};
+    /// use numpy::{Ix3, PyArray, PyArrayModule};
     /// let gil = pyo3::Python::acquire_gil();
     /// let np = PyArrayModule::import(gil.python()).unwrap();
-    /// let arr = PyArray::new::<f64>(gil.python(), &np, &[4, 5, 6]);
+    /// let arr = PyArray::<f64, Ix3>::new(gil.python(), &np, &[4, 5, 6]);
     /// assert_eq!(arr.strides(), &[240, 48, 8]);
     /// # }
     /// ```
@@ -242,9 +161,16 @@ impl PyArray {
         }
     }
 
-    unsafe fn data<T>(&self) -> *mut T {
+    pub fn typenum(&self) -> i32 {
+        unsafe {
+            let descr = (*self.as_array_ptr()).descr;
+            (*descr).type_num
+        }
+    }
+
+    pub(crate) unsafe fn data(&self) -> *mut c_void {
         let ptr = self.as_array_ptr();
-        (*ptr).data as *mut T
+        (*ptr).data as *mut c_void
     }
 
     fn ndarray_shape<A>(&self) -> StrideShape<IxDyn> {
@@ -253,22 +179,94 @@ impl PyArray {
         let st: Vec<usize> = self
             .strides()
             .iter()
-            .map(|&x| x as usize / ::std::mem::size_of::<A>())
+            .map(|&x| (x / size_of::<A>() as isize) as usize)
             .collect();
         shape.strides(Dim(st))
     }
 
-    pub fn typenum(&self) -> i32 {
-        unsafe {
-            let descr = (*self.as_array_ptr()).descr;
-            (*descr).type_num
+    /// Erase the static dimensionality of this array, yielding the dynamic form.
+    pub fn into_dyn(self) -> PyArray<T, IxDyn> {
+        PyArray(self.0, PhantomData)
+    }
+
+    /// Returns `true` if the array's memory is laid out C-style (row-major):
+    /// walking from the last axis to the first, each axis's stride equals
+    /// the stride of the previous axis times its dimension.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{PyArray, PyArrayModule, SliceOrIndex};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let arr = PyArray::<i64, _>::from_vec(gil.python(), &np, vec![0, 1, 2, 3, 4, 5]);
+    /// assert!(arr.is_c_contiguous());
+    /// let strided = arr
+    ///     .slice(gil.python(), &np, &[SliceOrIndex::Slice { start: None, stop: None, step: 2 }])
+    ///     .unwrap();
+    /// assert!(!strided.is_c_contiguous());
+    /// assert!(strided.as_slice().is_err());
+    /// # }
+    /// ```
+    pub fn is_c_contiguous(&self) -> bool {
+        is_contiguous(self.shape(), self.strides(), size_of::<T>(), false)
+    }
+
+    /// Returns `true` if the array's memory is laid out Fortran-style
+    /// (column-major): the mirror image of [`is_c_contiguous`](#method.is_c_contiguous).
+    pub fn is_fortran_contiguous(&self) -> bool {
+        is_contiguous(self.shape(), self.strides(), size_of::<T>(), true)
+    }
+}
+
+fn is_contiguous(shape: &[usize], strides: &[isize], itemsize: usize, fortran_order: bool) -> bool {
+    let itemsize = itemsize as isize;
+    let mut expected = itemsize;
+    let axes: Box<dyn Iterator<Item = (&usize, &isize)>> = if fortran_order {
+        Box::new(shape.iter().zip(strides.iter()))
+    } else {
+        Box::new(shape.iter().zip(strides.iter()).rev())
+    };
+    for (&dim, &stride) in axes {
+        if dim > 1 {
+            if stride != expected || stride <= 0 || stride % itemsize != 0 {
+                return false;
+            }
         }
+        expected *= dim.max(1) as isize;
     }
+    true
+}
 
-    fn type_check<A: types::TypeNum>(&self) -> Result<(), ArrayCastError> {
-        let test = A::typenum();
+impl<T: TypeNum> PyArray<T, IxDyn> {
+    /// Try to give this dynamically-dimensioned array a concrete dimensionality `D`,
+    /// checking that the array's actual number of dimensions matches `D`.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{Ix1, Ix2, PyArray, PyArrayModule};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let arr = PyArray::<f64, Ix2>::zeros(gil.python(), &np, &[2, 3], numpy::NPY_ORDER::NPY_CORDER);
+    /// let dyn_arr = arr.into_dyn();
+    /// assert!(dyn_arr.cast::<Ix2>().is_ok());
+    /// assert!(dyn_arr.cast::<Ix1>().is_err());
+    /// # }
+    /// ```
+    pub fn cast<D: Dimension>(&self) -> Result<&PyArray<T, D>, ArrayCastError> {
+        match D::NDIM {
+            Some(n) if n != self.ndim() => Err(ArrayCastError::to_dim(n, self.ndim())),
+            _ => Ok(unsafe { &*(self as *const Self as *const PyArray<T, D>) }),
+        }
+    }
+}
+
+impl<T: TypeNum, D: Dimension> PyArray<T, D> {
+    pub(crate) fn type_check(&self) -> Result<(), ArrayCastError> {
+        let test = T::typenum();
         let truth = self.typenum();
-        if A::typenum() == self.typenum() {
+        if test == truth {
             Ok(())
         } else {
             Err(ArrayCastError::to_rust(test, truth))
@@ -276,46 +274,157 @@ impl PyArray {
     }
 
     /// Get data as a ndarray::ArrayView
-    pub fn as_array<A: types::TypeNum>(&self) -> Result<ArrayViewD<A>, ArrayCastError> {
-        self.type_check::<A>()?;
+    pub fn as_array(&self) -> Result<ArrayView<T, D>, ArrayCastError> {
+        self.type_check()?;
         unsafe {
-            Ok(ArrayView::from_shape_ptr(
-                self.ndarray_shape::<A>(),
-                self.data(),
-            ))
+            let view = ArrayViewD::from_shape_ptr(self.ndarray_shape::<T>(), self.data() as *mut T);
+            Ok(view
+                .into_dimensionality::<D>()
+                .expect("PyArray: stored dimensionality doesn't match D"))
         }
     }
 
     /// Get data as a ndarray::ArrayViewMut
-    pub fn as_array_mut<A: types::TypeNum>(&self) -> Result<ArrayViewMutD<A>, ArrayCastError> {
-        self.type_check::<A>()?;
+    pub fn as_array_mut(&self) -> Result<ArrayViewMut<T, D>, ArrayCastError> {
+        self.type_check()?;
         unsafe {
-            Ok(ArrayViewMut::from_shape_ptr(
-                self.ndarray_shape::<A>(),
-                self.data(),
-            ))
+            let view =
+                ArrayViewMutD::from_shape_ptr(self.ndarray_shape::<T>(), self.data() as *mut T);
+            Ok(view
+                .into_dimensionality::<D>()
+                .expect("PyArray: stored dimensionality doesn't match D"))
         }
     }
 
-    /// Get data as a Rust immutable slice
-    pub fn as_slice<A: types::TypeNum>(&self) -> Result<&[A], ArrayCastError> {
-        self.type_check::<A>()?;
-        unsafe { Ok(::std::slice::from_raw_parts(self.data(), self.len())) }
+    /// Get data as a Rust immutable slice.
+    ///
+    /// Fails with [`NotContiguousError`](../error/enum.NotContiguousError.html)
+    /// if the array isn't C- or Fortran-contiguous (e.g. it was produced by
+    /// slicing, transposing, or broadcasting) - use [`as_array`](#method.as_array)
+    /// instead in that case.
+    ///
+    /// This is an unsafe primitive: it doesn't check for aliasing with other
+    /// outstanding views of the same buffer. Prefer [`readonly`](#method.readonly).
+    pub fn as_slice(&self) -> Result<&[T], NotContiguousError> {
+        self.type_check()?;
+        if !self.is_c_contiguous() && !self.is_fortran_contiguous() {
+            return Err(NotContiguousError::NotContiguous);
+        }
+        unsafe { Ok(::std::slice::from_raw_parts(self.data() as *mut T, self.len())) }
     }
 
-    /// Get data as a Rust mutable slice
-    pub fn as_slice_mut<A: types::TypeNum>(&self) -> Result<&mut [A], ArrayCastError> {
-        self.type_check::<A>()?;
-        unsafe { Ok(::std::slice::from_raw_parts_mut(self.data(), self.len())) }
+    /// Get data as a Rust mutable slice.
+    ///
+    /// Fails with [`NotContiguousError`](../error/enum.NotContiguousError.html)
+    /// under the same conditions as [`as_slice`](#method.as_slice).
+    ///
+    /// This is an unsafe primitive: it doesn't check for aliasing with other
+    /// outstanding views of the same buffer. Prefer [`readwrite`](#method.readwrite).
+    pub fn as_slice_mut(&self) -> Result<&mut [T], NotContiguousError> {
+        self.type_check()?;
+        if !self.is_c_contiguous() && !self.is_fortran_contiguous() {
+            return Err(NotContiguousError::NotContiguous);
+        }
+        unsafe { Ok(::std::slice::from_raw_parts_mut(self.data() as *mut T, self.len())) }
     }
 
-    pub unsafe fn new_<T: types::TypeNum>(
+    /// Get a runtime-borrow-checked, read-only view of this array's data.
+    ///
+    /// Fails with a [`BorrowError`](../borrow/struct.BorrowError.html) if an
+    /// overlapping [`readwrite`](#method.readwrite) borrow is still alive.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{PyArray, PyArrayModule};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let pyarray = PyArray::<u32, _>::from_vec(gil.python(), &np, vec![1, 2, 3]);
+    /// let ro = pyarray.readonly().unwrap();
+    /// assert_eq!(ro.as_slice(), &[1, 2, 3]);
+    /// # }
+    /// ```
+    ///
+    /// Two strided views that alias the same element are rejected even
+    /// though their nominal `[start, start + len)` ranges don't overlap:
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{PyArray, PyArrayModule, SliceOrIndex};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let arr = PyArray::<i64, _>::from_vec(gil.python(), &np, vec![0, 1, 2, 3, 4]);
+    /// // elements [0, 2], stride 2
+    /// let a = arr
+    ///     .slice(gil.python(), &np, &[SliceOrIndex::Slice { start: Some(0), stop: Some(3), step: 2 }])
+    ///     .unwrap();
+    /// // element [2], stride 2 - aliases `a`'s second element
+    /// let b = arr
+    ///     .slice(gil.python(), &np, &[SliceOrIndex::Slice { start: Some(2), stop: Some(3), step: 2 }])
+    ///     .unwrap();
+    /// let rw = a.readwrite().unwrap();
+    /// assert!(b.readwrite().is_err());
+    /// drop(rw);
+    /// assert!(b.readwrite().is_ok());
+    /// # }
+    /// ```
+    ///
+    /// Two `readonly` borrows of overlapping, but not identical, views are
+    /// fine to hold at the same time:
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{PyArray, PyArrayModule, SliceOrIndex};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let arr = PyArray::<i64, _>::from_vec(gil.python(), &np, vec![0, 1, 2, 3, 4]);
+    /// let a = arr.readonly().unwrap();
+    /// let sub = arr
+    ///     .slice(gil.python(), &np, &[SliceOrIndex::Slice { start: Some(0), stop: Some(3), step: 1 }])
+    ///     .unwrap();
+    /// let b = sub.readonly();
+    /// assert!(b.is_ok());
+    /// # }
+    /// ```
+    pub fn readonly(&self) -> Result<borrow::PyReadonlyArray<T, D>, borrow::BorrowError> {
+        borrow::PyReadonlyArray::try_new(self)
+    }
+
+    /// Get a runtime-borrow-checked, exclusive view of this array's data.
+    ///
+    /// Fails with a [`BorrowError`](../borrow/struct.BorrowError.html) if any
+    /// other outstanding borrow of an overlapping region exists.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{PyArray, PyArrayModule};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let pyarray = PyArray::<u32, _>::from_vec(gil.python(), &np, vec![1, 2, 3]);
+    /// let rw = pyarray.readwrite().unwrap();
+    /// // an overlapping readonly borrow is rejected while `rw` is alive
+    /// assert!(pyarray.readonly().is_err());
+    /// drop(rw);
+    /// // once the exclusive borrow is dropped, a new one can be acquired
+    /// assert!(pyarray.readonly().is_ok());
+    /// # }
+    /// ```
+    pub fn readwrite(&self) -> Result<borrow::PyReadwriteArray<T, D>, borrow::BorrowError> {
+        borrow::PyReadwriteArray::try_new(self)
+    }
+
+    pub unsafe fn new_(
         py: Python,
         np: &PyArrayModule,
         dims: &[usize],
         strides: *mut npy_intp,
         data: *mut c_void,
     ) -> Self {
+        assert!(
+            D::NDIM.map_or(true, |n| n == dims.len()),
+            "PyArray::new_: dims.len() ({}) doesn't match D::NDIM ({:?})",
+            dims.len(),
+            D::NDIM
+        );
         let dims: Vec<_> = dims.iter().map(|d| *d as npy_intp).collect();
         let ptr = np.PyArray_New(
             np.get_type_object(npyffi::ArrayType::PyArray_Type),
@@ -332,17 +441,31 @@ impl PyArray {
     }
 
     /// a wrapper of [PyArray_SimpleNew](https://docs.scipy.org/doc/numpy/reference/c-api.array.html#c.PyArray_SimpleNew)
-    pub fn new<T: TypeNum>(py: Python, np: &PyArrayModule, dims: &[usize]) -> Self {
-        unsafe { Self::new_::<T>(py, np, dims, null_mut(), null_mut()) }
+    ///
+    /// The number of entries in `dims` must match `D`.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{Ix3, PyArray, PyArrayModule};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let arr = PyArray::<f64, Ix3>::new(gil.python(), &np, &[4, 5, 6]);
+    /// assert_eq!(arr.shape(), &[4, 5, 6]);
+    /// # }
+    /// ```
+    pub fn new(py: Python, np: &PyArrayModule, dims: &[usize]) -> Self {
+        unsafe { Self::new_(py, np, dims, null_mut(), null_mut()) }
     }
 
     /// a wrapper of [PyArray_ZEROS](https://docs.scipy.org/doc/numpy/reference/c-api.array.html#c.PyArray_ZEROS)
-    pub fn zeros<T: TypeNum>(
-        py: Python,
-        np: &PyArrayModule,
-        dims: &[usize],
-        order: NPY_ORDER,
-    ) -> Self {
+    pub fn zeros(py: Python, np: &PyArrayModule, dims: &[usize], order: NPY_ORDER) -> Self {
+        assert!(
+            D::NDIM.map_or(true, |n| n == dims.len()),
+            "PyArray::zeros: dims.len() ({}) doesn't match D::NDIM ({:?})",
+            dims.len(),
+            D::NDIM
+        );
         let dims: Vec<npy_intp> = dims.iter().map(|d| *d as npy_intp).collect();
         unsafe {
             let descr = np.PyArray_DescrFromType(T::typenum());
@@ -355,18 +478,491 @@ impl PyArray {
             Self::from_owned_ptr(py, ptr)
         }
     }
+}
+
+impl<T: TypeNum> PyArray<T, Ix1> {
+    /// Construct one-dimension PyArray from boxed slice.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{PyArray, PyArrayModule};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let slice = vec![1, 2, 3, 4, 5].into_boxed_slice();
+    /// let pyarray = PyArray::<u32, _>::from_boxed_slice(gil.python(), &np, slice);
+    /// assert_eq!(pyarray.as_slice().unwrap(), &[1, 2, 3, 4, 5]);
+    /// # }
+    /// ```
+    pub fn from_boxed_slice(py: Python, np: &PyArrayModule, v: Box<[T]>) -> Self
+    where
+        T: 'static + Send,
+    {
+        let dims = [v.len()];
+        let data = v.as_ptr() as *mut c_void;
+        unsafe {
+            let arr = PyArray::new_(py, np, &dims, null_mut(), data);
+            slice_container::set_base_object(
+                py,
+                arr.as_array_ptr(),
+                np,
+                slice_container::PySliceContainer::from_boxed_slice(v),
+            );
+            arr
+        }
+    }
+
+    /// Construct one-dimension PyArray from Vec.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{PyArray, PyArrayModule};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let pyarray = PyArray::<u32, _>::from_vec(gil.python(), &np, vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(pyarray.as_slice().unwrap(), &[1, 2, 3, 4, 5]);
+    /// # }
+    /// ```
+    pub fn from_vec(py: Python, np: &PyArrayModule, v: Vec<T>) -> Self
+    where
+        T: 'static + Send,
+    {
+        Self::from_boxed_slice(py, np, v.into_boxed_slice())
+    }
 
     /// a wrapper of [PyArray_Arange](https://docs.scipy.org/doc/numpy/reference/c-api.array.html#c.PyArray_Arange)
-    pub fn arange<T: TypeNum>(
-        py: Python,
-        np: &PyArrayModule,
-        start: f64,
-        stop: f64,
-        step: f64,
-    ) -> Self {
+    pub fn arange(py: Python, np: &PyArrayModule, start: f64, stop: f64, step: f64) -> Self {
         unsafe {
             let ptr = np.PyArray_Arange(start, stop, step, T::typenum());
             Self::from_owned_ptr(py, ptr)
         }
     }
 }
+
+impl<T: TypeNum> PyArray<T, Ix2> {
+    /// Construct a two-dimension PyArray from `Vec<Vec<T>>`.
+    ///
+    /// This function checks all dimension of inner vec, and if there's any vec
+    /// where its dimension differs from others, it returns `ArrayCastError`.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; #[macro_use] extern crate ndarray; fn main() {
+    /// use numpy::{PyArray, PyArrayModule};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let vec2 = vec![vec![1, 2, 3]; 2];
+    /// let pyarray = PyArray::<u32, _>::from_vec2(gil.python(), &np, &vec2).unwrap();
+    /// assert_eq!(pyarray.as_array().unwrap(), array![[1, 2, 3], [1, 2, 3]]);
+    /// assert!(PyArray::<u32, _>::from_vec2(gil.python(), &np, &vec![vec![1], vec![2, 3]]).is_err());
+    /// # }
+    /// ```
+    pub fn from_vec2(py: Python, np: &PyArrayModule, v: &Vec<Vec<T>>) -> Result<Self, ArrayCastError>
+    where
+        T: 'static + Send,
+    {
+        let last_len = v.last().map_or(0, |v| v.len());
+        if v.iter().any(|v| v.len() != last_len) {
+            return Err(ArrayCastError::FromVec);
+        }
+        let dims = [v.len(), last_len];
+        let flattend: Vec<_> = v.iter().cloned().flatten().collect();
+        let boxed = flattend.into_boxed_slice();
+        let data = boxed.as_ptr() as *mut c_void;
+        unsafe {
+            let arr = PyArray::new_(py, np, &dims, null_mut(), data);
+            slice_container::set_base_object(
+                py,
+                arr.as_array_ptr(),
+                np,
+                slice_container::PySliceContainer::from_boxed_slice(boxed),
+            );
+            Ok(arr)
+        }
+    }
+}
+
+impl<T: TypeNum> PyArray<T, Ix3> {
+    /// Construct a three-dimension PyArray from `Vec<Vec<Vec<T>>>`.
+    ///
+    /// This function checks all dimension of inner vec, and if there's any vec
+    /// where its dimension differs from others, it returns `ArrayCastError`.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; #[macro_use] extern crate ndarray; fn main() {
+    /// use numpy::{PyArray, PyArrayModule};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let vec2 = vec![vec![vec![1, 2]; 2]; 2];
+    /// let pyarray = PyArray::<u32, _>::from_vec3(gil.python(), &np, &vec2).unwrap();
+    /// assert_eq!(
+    ///     pyarray.as_array().unwrap(),
+    ///     array![[[1, 2], [1, 2]], [[1, 2], [1, 2]]]
+    /// );
+    /// assert!(PyArray::<u32, _>::from_vec3(gil.python(), &np, &vec![vec![vec![1], vec![]]]).is_err());
+    /// # }
+    /// ```
+    pub fn from_vec3(
+        py: Python,
+        np: &PyArrayModule,
+        v: &Vec<Vec<Vec<T>>>,
+    ) -> Result<Self, ArrayCastError>
+    where
+        T: 'static + Send,
+    {
+        let dim2 = v.last().map_or(0, |v| v.len());
+        if v.iter().any(|v| v.len() != dim2) {
+            return Err(ArrayCastError::FromVec);
+        }
+        let dim3 = v.last().map_or(0, |v| v.last().map_or(0, |v| v.len()));
+        if v.iter().any(|v| v.iter().any(|v| v.len() != dim3)) {
+            return Err(ArrayCastError::FromVec);
+        }
+        let dims = [v.len(), dim2, dim3];
+        let flattend: Vec<_> = v.iter().flat_map(|v| v.iter().cloned().flatten()).collect();
+        let boxed = flattend.into_boxed_slice();
+        let data = boxed.as_ptr() as *mut c_void;
+        unsafe {
+            let arr = PyArray::new_(py, np, &dims, null_mut(), data);
+            slice_container::set_base_object(
+                py,
+                arr.as_array_ptr(),
+                np,
+                slice_container::PySliceContainer::from_boxed_slice(boxed),
+            );
+            Ok(arr)
+        }
+    }
+}
+
+impl<T: TypeNum, D: Dimension> PyArray<T, D> {
+    /// Construct PyArray from ndarray::Array, preserving its dimensionality.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; #[macro_use] extern crate ndarray; fn main() {
+    /// use numpy::{PyArray, PyArrayModule};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let pyarray = PyArray::from_ndarray(gil.python(), &np, array![[1, 2], [3, 4]]);
+    /// assert_eq!(pyarray.as_array().unwrap(), array![[1, 2], [3, 4]]);
+    /// # }
+    /// ```
+    pub fn from_ndarray(py: Python, np: &PyArrayModule, arr: Array<T, D>) -> Self
+    where
+        T: 'static + Send,
+    {
+        let dims = arr.shape().to_vec();
+        let mut strides: Vec<npy_intp> = arr
+            .strides()
+            .iter()
+            .map(|&s| (s * size_of::<T>() as isize) as npy_intp)
+            .collect();
+        let raw = arr.into_raw_vec().into_boxed_slice();
+        let data = raw.as_ptr() as *mut c_void;
+        unsafe {
+            let result = PyArray::new_(py, np, &dims, strides.as_mut_ptr(), data);
+            slice_container::set_base_object(
+                py,
+                result.as_array_ptr(),
+                np,
+                slice_container::PySliceContainer::from_boxed_slice(raw),
+            );
+            result
+        }
+    }
+}
+
+/// A single axis selector for [`PyArray::slice`](struct.PyArray.html#method.slice),
+/// mirroring NumPy's basic indexing.
+#[derive(Clone, Copy, Debug)]
+pub enum SliceOrIndex {
+    /// Select one element along this axis; the axis is dropped from the result.
+    /// Negative indices count from the end, as in Python.
+    Index(isize),
+    /// Select `[start, stop)` stepping by `step`, with Python slice semantics:
+    /// negative indices count from the end, `step` may be negative, and
+    /// omitted bounds default to the full extent in the direction of `step`.
+    Slice {
+        start: Option<isize>,
+        stop: Option<isize>,
+        step: isize,
+    },
+}
+
+/// Normalize a single axis's `(start, stop, step)` against its dimension,
+/// returning the byte offset's element index, the element step, and the
+/// resulting output length.
+fn normalize_slice(
+    start: Option<isize>,
+    stop: Option<isize>,
+    step: isize,
+    dim: usize,
+) -> (isize, isize, usize) {
+    let dim = dim as isize;
+    let clamp = |i: isize, lo: isize, hi: isize| i.max(lo).min(hi);
+    let norm = |i: isize| if i < 0 { i + dim } else { i };
+    let start = match start {
+        Some(i) => clamp(norm(i), if step > 0 { 0 } else { -1 }, if step > 0 { dim } else { dim - 1 }),
+        None => if step > 0 { 0 } else { dim - 1 },
+    };
+    let stop = match stop {
+        Some(i) => clamp(norm(i), -1, dim),
+        None => if step > 0 { dim } else { -1 },
+    };
+    let len = if step > 0 {
+        if stop > start {
+            ((stop - start + step - 1) / step) as usize
+        } else {
+            0
+        }
+    } else {
+        if stop < start {
+            ((start - stop - step - 1) / -step) as usize
+        } else {
+            0
+        }
+    };
+    (start, step, len)
+}
+
+impl<T: TypeNum, D> PyArray<T, D> {
+    /// General basic indexing, mirroring `numpy`'s own semantics: given one
+    /// [`SliceOrIndex`](enum.SliceOrIndex.html) per axis, returns a new
+    /// zero-copy, strided view into the same buffer. The returned array
+    /// keeps `self` alive via its `base` object.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{PyArray, PyArrayModule, SliceOrIndex};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let arr = PyArray::<i64, _>::from_vec(gil.python(), &np, vec![0, 1, 2, 3, 4]);
+    /// // reverse the whole array, mirroring Python's `arr[::-1]`
+    /// let reversed = arr
+    ///     .slice(gil.python(), &np, &[SliceOrIndex::Slice { start: None, stop: None, step: -1 }])
+    ///     .unwrap();
+    /// assert_eq!(reversed.shape(), &[5]);
+    /// assert_eq!(reversed.as_array().unwrap().to_owned().into_raw_vec(), vec![4, 3, 2, 1, 0]);
+    /// // an out-of-range negative start with a negative step yields an empty
+    /// // slice, matching Python's `slice(-100, None, -1).indices(5)`
+    /// let empty = arr
+    ///     .slice(gil.python(), &np, &[SliceOrIndex::Slice { start: Some(-100), stop: None, step: -1 }])
+    ///     .unwrap();
+    /// assert_eq!(empty.shape(), &[0]);
+    /// // a plain `Index` selector and a `Slice` with a non-zero `start`
+    /// // both require offsetting the data pointer by a non-zero amount
+    /// let arr2d = PyArray::<i64, _>::from_vec2(
+    ///     gil.python(), &np, &vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]],
+    /// ).unwrap();
+    /// let tail = arr2d
+    ///     .slice(gil.python(), &np, &[
+    ///         SliceOrIndex::Index(1),
+    ///         SliceOrIndex::Slice { start: Some(1), stop: None, step: 1 },
+    ///     ])
+    ///     .unwrap();
+    /// assert_eq!(tail.shape(), &[2]);
+    /// assert_eq!(tail.as_array().unwrap().to_owned().into_raw_vec(), vec![4, 5]);
+    /// # }
+    /// ```
+    pub fn slice(
+        &self,
+        py: Python,
+        np: &PyArrayModule,
+        indices: &[SliceOrIndex],
+    ) -> Result<PyArrayDyn<T>, IndexError> {
+        let shape = self.shape();
+        let strides = self.strides();
+        if indices.len() != shape.len() {
+            return Err(IndexError::ndim(shape.len(), indices.len()));
+        }
+        let mut offset: isize = 0;
+        let mut new_shape = Vec::with_capacity(shape.len());
+        let mut new_strides = Vec::with_capacity(shape.len());
+        for (i, sel) in indices.iter().enumerate() {
+            match *sel {
+                SliceOrIndex::Index(idx) => {
+                    let idx = if idx < 0 { idx + shape[i] as isize } else { idx };
+                    if idx < 0 || idx >= shape[i] as isize {
+                        return Err(IndexError::out_of_bounds(i, shape[i]));
+                    }
+                    offset += idx * strides[i];
+                }
+                SliceOrIndex::Slice { start, stop, step } => {
+                    if step == 0 {
+                        return Err(IndexError::zero_step());
+                    }
+                    let (start, step, len) = normalize_slice(start, stop, step, shape[i]);
+                    offset += start * strides[i];
+                    new_shape.push(len);
+                    new_strides.push(strides[i] * step);
+                }
+            }
+        }
+        unsafe {
+            let data = (self.data() as *mut u8).offset(offset) as *mut c_void;
+            let mut np_strides: Vec<npy_intp> = new_strides.iter().map(|&s| s as npy_intp).collect();
+            let view = PyArray::<T, IxDyn>::new_(
+                py,
+                np,
+                &new_shape,
+                np_strides.as_mut_ptr(),
+                data,
+            );
+            let base = self.0.clone_ref(py);
+            np.PyArray_SetBaseObject(view.as_array_ptr(), base.into_ptr());
+            Ok(view)
+        }
+    }
+}
+
+/// Compute the C-order (row-major) strides, in bytes, for `dims`.
+fn standard_strides(dims: &[usize], itemsize: usize) -> Vec<npy_intp> {
+    let mut strides = vec![0 as npy_intp; dims.len()];
+    let mut acc = itemsize as npy_intp;
+    for (stride, &dim) in strides.iter_mut().zip(dims.iter()).rev() {
+        *stride = acc;
+        acc *= dim.max(1) as npy_intp;
+    }
+    strides
+}
+
+impl<T: TypeNum, D> PyArray<T, D> {
+    /// Permute this array's axes, returning a new zero-copy view parented
+    /// to `self`. With `axes = None`, reverses all axes (NumPy's default).
+    ///
+    /// Fails with [`IndexError`](../error/struct.IndexError.html) if `axes`
+    /// isn't a permutation of `0..self.ndim()`.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{PyArray, PyArrayModule};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let arr = PyArray::<i64, _>::from_vec2(
+    ///     gil.python(), &np, &vec![vec![0, 1, 2], vec![3, 4, 5]],
+    /// ).unwrap();
+    /// // the default, with `axes = None`, reverses all axes
+    /// let reversed = arr.transpose(gil.python(), &np, None).unwrap();
+    /// assert_eq!(reversed.shape(), &[3, 2]);
+    /// // an explicit permutation picks out the same axes in that order
+    /// let same = arr.transpose(gil.python(), &np, Some(&[0, 1])).unwrap();
+    /// assert_eq!(same.shape(), &[2, 3]);
+    /// // a non-permutation - wrong length, an out-of-range axis, or a
+    /// // repeated axis - is rejected instead of silently misbehaving
+    /// assert!(arr.transpose(gil.python(), &np, Some(&[0])).is_err());
+    /// assert!(arr.transpose(gil.python(), &np, Some(&[0, 2])).is_err());
+    /// assert!(arr.transpose(gil.python(), &np, Some(&[0, 0])).is_err());
+    /// # }
+    /// ```
+    pub fn transpose(
+        &self,
+        py: Python,
+        np: &PyArrayModule,
+        axes: Option<&[usize]>,
+    ) -> Result<PyArrayDyn<T>, IndexError> {
+        let shape = self.shape();
+        let strides = self.strides();
+        let ndim = shape.len();
+        let axes: Vec<usize> = match axes {
+            Some(axes) => axes.to_vec(),
+            None => (0..ndim).rev().collect(),
+        };
+        if axes.len() != ndim {
+            return Err(IndexError::ndim(ndim, axes.len()));
+        }
+        let mut seen = vec![false; ndim];
+        for &a in &axes {
+            if a >= ndim || seen[a] {
+                return Err(IndexError::out_of_bounds(a, ndim));
+            }
+            seen[a] = true;
+        }
+        let new_shape: Vec<usize> = axes.iter().map(|&a| shape[a]).collect();
+        let mut new_strides: Vec<npy_intp> = axes.iter().map(|&a| strides[a] as npy_intp).collect();
+        unsafe {
+            let view = PyArray::<T, IxDyn>::new_(
+                py,
+                np,
+                &new_shape,
+                new_strides.as_mut_ptr(),
+                self.data(),
+            );
+            let base = self.0.clone_ref(py);
+            np.PyArray_SetBaseObject(view.as_array_ptr(), base.into_ptr());
+            Ok(view)
+        }
+    }
+
+    /// Reshape this array to `dims`. Returns a zero-copy view reusing the
+    /// existing buffer when `self` is C-contiguous; otherwise allocates a
+    /// fresh contiguous buffer and copies elements in row-major order.
+    /// Fails with [`ReshapeError`](../error/struct.ReshapeError.html) if the
+    /// element counts don't match.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{PyArray, PyArrayModule, SliceOrIndex};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let arr = PyArray::<i64, _>::from_vec(gil.python(), &np, vec![0, 1, 2, 3, 4, 5]);
+    /// // `arr` is C-contiguous, so reshaping reuses its buffer as a view
+    /// let reshaped = arr.reshape(gil.python(), &np, &[2, 3]).unwrap();
+    /// assert_eq!(reshaped.shape(), &[2, 3]);
+    /// assert_eq!(reshaped.as_array().unwrap().to_owned().into_raw_vec(), vec![0, 1, 2, 3, 4, 5]);
+    ///
+    /// // a non-contiguous array (here, reversed) can't be reshaped in place,
+    /// // so this allocates a fresh buffer and copies instead
+    /// let reversed = arr
+    ///     .slice(gil.python(), &np, &[SliceOrIndex::Slice { start: None, stop: None, step: -1 }])
+    ///     .unwrap();
+    /// assert!(!reversed.is_c_contiguous());
+    /// let copied = reversed.reshape(gil.python(), &np, &[3, 2]).unwrap();
+    /// assert_eq!(copied.as_array().unwrap().to_owned().into_raw_vec(), vec![5, 4, 3, 2, 1, 0]);
+    ///
+    /// // mismatched element counts are rejected
+    /// assert!(arr.reshape(gil.python(), &np, &[4, 4]).is_err());
+    /// # }
+    /// ```
+    pub fn reshape(
+        &self,
+        py: Python,
+        np: &PyArrayModule,
+        dims: &[usize],
+    ) -> Result<PyArrayDyn<T>, ReshapeError>
+    where
+        T: 'static + Send,
+    {
+        let new_len: usize = dims.iter().product();
+        if new_len != self.len() {
+            return Err(ReshapeError::size_mismatch(self.len(), new_len));
+        }
+        unsafe {
+            if self.is_c_contiguous() {
+                let mut strides = standard_strides(dims, size_of::<T>());
+                let view = PyArray::<T, IxDyn>::new_(py, np, dims, strides.as_mut_ptr(), self.data());
+                let base = self.0.clone_ref(py);
+                np.PyArray_SetBaseObject(view.as_array_ptr(), base.into_ptr());
+                Ok(view)
+            } else {
+                let view = ArrayViewD::<T>::from_shape_ptr(self.ndarray_shape::<T>(), self.data() as *mut T);
+                let flat: Vec<T> = view.iter().cloned().collect();
+                let boxed = flat.into_boxed_slice();
+                let data = boxed.as_ptr() as *mut c_void;
+                let arr = PyArray::<T, IxDyn>::new_(py, np, dims, null_mut(), data);
+                slice_container::set_base_object(
+                    py,
+                    arr.as_array_ptr(),
+                    np,
+                    slice_container::PySliceContainer::from_boxed_slice(boxed),
+                );
+                Ok(arr)
+            }
+        }
+    }
+}
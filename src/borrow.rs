@@ -0,0 +1,253 @@
+//! Runtime borrow checking for `PyArray`, modeled on `std::cell::RefCell`.
+//!
+//! NumPy does not prevent two Rust views from aliasing the same buffer, so
+//! `PyArray::as_slice_mut`/`as_array_mut` are unsafe primitives. `readonly`
+//! and `readwrite` wrap them in a guard that is tracked in a process-global
+//! registry keyed by the array's underlying data pointer and byte extent,
+//! so overlapping borrows are rejected instead of producing aliased
+//! `&mut` references.
+
+use ndarray::{ArrayView, ArrayViewMut, Dimension};
+use pyo3::PyErr;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+
+use super::array::PyArray;
+use super::error::ArrayCastError;
+use super::types::TypeNum;
+
+/// A borrowed region of a NumPy array's data buffer.
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+struct Extent {
+    ptr: usize,
+    len: usize,
+}
+
+impl Extent {
+    fn overlaps(&self, other: &Extent) -> bool {
+        self.ptr < other.ptr + other.len && other.ptr < self.ptr + self.len
+    }
+}
+
+enum BorrowState {
+    Shared(usize),
+    Exclusive,
+}
+
+lazy_static! {
+    static ref BORROW_REGISTRY: Mutex<HashMap<Extent, BorrowState>> = Mutex::new(HashMap::new());
+}
+
+fn conflicts(registry: &HashMap<Extent, BorrowState>, extent: &Extent) -> bool {
+    registry.keys().any(|other| other.overlaps(extent))
+}
+
+/// Whether an overlapping entry would conflict with a *new shared* borrow of
+/// `extent`, i.e. whether an overlapping entry is `Exclusive`. Two
+/// overlapping `Shared` entries never conflict with each other, even if
+/// their extents aren't identical (think `arr.readonly()` and
+/// `sub.readonly()` for some slice `sub` of `arr`) — that's the whole point
+/// of `BorrowState::Shared` carrying a count instead of being a single flag.
+fn exclusive_conflict(registry: &HashMap<Extent, BorrowState>, extent: &Extent) -> bool {
+    registry
+        .iter()
+        .any(|(other, state)| other.overlaps(extent) && matches!(state, BorrowState::Exclusive))
+}
+
+/// Error returned when acquiring a `readonly`/`readwrite` guard would alias
+/// an existing borrow of the same (or an overlapping) region of memory, or
+/// when the array's dtype doesn't match `T`.
+#[derive(Debug)]
+pub struct BorrowError {
+    pub(crate) already_borrowed_as: &'static str,
+}
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Cannot acquire this borrow: array is already borrowed as {}",
+            self.already_borrowed_as
+        )
+    }
+}
+
+impl std::error::Error for BorrowError {}
+
+impl std::convert::From<BorrowError> for PyErr {
+    fn from(err: BorrowError) -> PyErr {
+        PyErr::new::<pyo3::exc::RuntimeError, _>(err.to_string())
+    }
+}
+
+fn acquire_shared(extent: Extent) -> Result<(), BorrowError> {
+    let mut registry = BORROW_REGISTRY.lock().unwrap();
+    match registry.get_mut(&extent) {
+        Some(BorrowState::Shared(n)) => {
+            *n += 1;
+            Ok(())
+        }
+        Some(BorrowState::Exclusive) => Err(BorrowError {
+            already_borrowed_as: "readwrite",
+        }),
+        None => {
+            if exclusive_conflict(&registry, &extent) {
+                return Err(BorrowError {
+                    already_borrowed_as: "readwrite",
+                });
+            }
+            registry.insert(extent, BorrowState::Shared(1));
+            Ok(())
+        }
+    }
+}
+
+fn acquire_exclusive(extent: Extent) -> Result<(), BorrowError> {
+    let mut registry = BORROW_REGISTRY.lock().unwrap();
+    if conflicts(&registry, &extent) {
+        let already_borrowed_as = if exclusive_conflict(&registry, &extent) {
+            "readwrite"
+        } else {
+            "readonly"
+        };
+        return Err(BorrowError { already_borrowed_as });
+    }
+    registry.insert(extent, BorrowState::Exclusive);
+    Ok(())
+}
+
+fn release_shared(extent: Extent) {
+    let mut registry = BORROW_REGISTRY.lock().unwrap();
+    let done = match registry.get_mut(&extent) {
+        Some(BorrowState::Shared(n)) => {
+            *n -= 1;
+            *n == 0
+        }
+        _ => true,
+    };
+    if done {
+        registry.remove(&extent);
+    }
+}
+
+fn release_exclusive(extent: Extent) {
+    BORROW_REGISTRY.lock().unwrap().remove(&extent);
+}
+
+/// Compute the `[low, high)` byte range `array` actually touches, accounting
+/// for strided (possibly negative-stride) views: `array.data()` is only the
+/// address of element `0` along every axis, so a negative stride reaches
+/// *backward* from it and a non-unit stride can leave gaps `data() +
+/// len() * size_of::<T>()` doesn't cover.
+fn extent_of<T, D>(array: &PyArray<T, D>) -> Extent
+where
+    T: TypeNum,
+    D: Dimension,
+{
+    let base = unsafe { array.data() } as isize;
+    if array.len() == 0 {
+        return Extent {
+            ptr: base as usize,
+            len: 0,
+        };
+    }
+    let itemsize = std::mem::size_of::<T>() as isize;
+    let mut low: isize = 0;
+    let mut high: isize = 0;
+    for (&dim, &stride) in array.shape().iter().zip(array.strides()) {
+        let span = (dim - 1) as isize * stride;
+        if span >= 0 {
+            high += span;
+        } else {
+            low += span;
+        }
+    }
+    Extent {
+        ptr: (base + low) as usize,
+        len: (high - low + itemsize) as usize,
+    }
+}
+
+/// A shared, read-only view of a `PyArray`'s data, checked against
+/// concurrent exclusive borrows at runtime.
+pub struct PyReadonlyArray<'a, T: TypeNum, D: Dimension> {
+    array: &'a PyArray<T, D>,
+    extent: Extent,
+}
+
+impl<'a, T: TypeNum, D: Dimension> PyReadonlyArray<'a, T, D> {
+    pub(crate) fn try_new(array: &'a PyArray<T, D>) -> Result<Self, BorrowError> {
+        array.type_check().map_err(BorrowError::from)?;
+        let extent = extent_of(array);
+        acquire_shared(extent)?;
+        Ok(PyReadonlyArray { array, extent })
+    }
+
+    /// Get the underlying data as an `ndarray::ArrayView`.
+    pub fn as_array(&self) -> ArrayView<T, D> {
+        self.array.as_array().expect("dtype validated at construction")
+    }
+
+    /// Get the underlying data as a Rust slice.
+    ///
+    /// Panics if the array isn't C- or Fortran-contiguous; use
+    /// [`as_array`](#method.as_array) for a strided view instead.
+    pub fn as_slice(&self) -> &[T] {
+        self.array.as_slice().expect("array is not contiguous")
+    }
+}
+
+impl<'a, T: TypeNum, D: Dimension> Drop for PyReadonlyArray<'a, T, D> {
+    fn drop(&mut self) {
+        release_shared(self.extent);
+    }
+}
+
+/// An exclusive, read-write view of a `PyArray`'s data, checked against any
+/// other outstanding borrow (shared or exclusive) at runtime.
+pub struct PyReadwriteArray<'a, T: TypeNum, D: Dimension> {
+    array: &'a PyArray<T, D>,
+    extent: Extent,
+}
+
+impl<'a, T: TypeNum, D: Dimension> PyReadwriteArray<'a, T, D> {
+    pub(crate) fn try_new(array: &'a PyArray<T, D>) -> Result<Self, BorrowError> {
+        array.type_check().map_err(BorrowError::from)?;
+        let extent = extent_of(array);
+        acquire_exclusive(extent)?;
+        Ok(PyReadwriteArray { array, extent })
+    }
+
+    /// Get the underlying data as an `ndarray::ArrayViewMut`.
+    pub fn as_array_mut(&mut self) -> ArrayViewMut<T, D> {
+        self.array
+            .as_array_mut()
+            .expect("dtype validated at construction")
+    }
+
+    /// Get the underlying data as a mutable Rust slice.
+    ///
+    /// Panics if the array isn't C- or Fortran-contiguous; use
+    /// [`as_array_mut`](#method.as_array_mut) for a strided view instead.
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        self.array
+            .as_slice_mut()
+            .expect("array is not contiguous")
+    }
+}
+
+impl<'a, T: TypeNum, D: Dimension> Drop for PyReadwriteArray<'a, T, D> {
+    fn drop(&mut self) {
+        release_exclusive(self.extent);
+    }
+}
+
+impl From<ArrayCastError> for BorrowError {
+    fn from(_: ArrayCastError) -> Self {
+        BorrowError {
+            already_borrowed_as: "a different dtype",
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! NumPy-style broadcasting for `PyArray`.
+//!
+//! Computes the broadcasted shape of two or more arrays and yields
+//! zero-copy strided views conforming to it, following the same rules as
+//! `numpy` itself: shapes are aligned by their trailing axes, each output
+//! dimension is the max of the inputs, and any axis of length 1 is
+//! "stretched" by giving it an output stride of 0.
+
+use ndarray::IxDyn;
+use pyo3::Python;
+
+use std::os::raw::c_void;
+
+use super::array::{PyArray, PyArrayDyn};
+use super::error::BroadcastError;
+use super::module::PyArrayModule;
+use super::npyffi;
+use super::types::TypeNum;
+
+/// Compute the shape that results from broadcasting `shapes` together.
+///
+/// # Example
+/// ```
+/// # extern crate numpy; fn main() {
+/// use numpy::broadcast_shape;
+/// assert_eq!(broadcast_shape(&[&[8, 1, 6, 1], &[7, 1, 5]]).unwrap(), &[8, 7, 6, 5]);
+/// assert!(broadcast_shape(&[&[3], &[4]]).is_err());
+/// # }
+/// ```
+pub fn broadcast_shape(shapes: &[&[usize]]) -> Result<Vec<usize>, BroadcastError> {
+    let ndim = shapes.iter().map(|s| s.len()).max().unwrap_or(0);
+    let mut out = vec![1usize; ndim];
+    for shape in shapes {
+        let pad = ndim - shape.len();
+        for (i, &dim) in shape.iter().enumerate() {
+            let axis = pad + i;
+            if dim == out[axis] || dim == 1 {
+                out[axis] = out[axis].max(dim);
+            } else if out[axis] == 1 {
+                out[axis] = dim;
+            } else {
+                return Err(BroadcastError::incompatible(shape, &out));
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Compute the padded, broadcast-compatible strides (in bytes) of `shape`/
+/// `strides` against a target `out_shape`: missing leading axes and any
+/// axis of length 1 get a stride of 0.
+fn broadcast_strides(
+    shape: &[usize],
+    strides: &[isize],
+    out_shape: &[usize],
+) -> Result<Vec<isize>, BroadcastError> {
+    let pad = out_shape.len() - shape.len();
+    let mut out = vec![0isize; out_shape.len()];
+    for (i, (&dim, &stride)) in shape.iter().zip(strides.iter()).enumerate() {
+        let axis = pad + i;
+        if dim == out_shape[axis] {
+            out[axis] = stride;
+        } else if dim == 1 {
+            out[axis] = 0;
+        } else {
+            return Err(BroadcastError::incompatible(shape, out_shape));
+        }
+    }
+    Ok(out)
+}
+
+impl<T: TypeNum, D> PyArray<T, D> {
+    /// Broadcast this array to `shape`, returning a new zero-copy view that
+    /// repeats elements along any stretched axis. Fails with
+    /// [`BroadcastError`](../error/struct.BroadcastError.html) if `shape`
+    /// isn't compatible with `self.shape()`.
+    ///
+    /// # Example
+    /// ```
+    /// # extern crate pyo3; extern crate numpy; fn main() {
+    /// use numpy::{PyArray, PyArrayModule};
+    /// let gil = pyo3::Python::acquire_gil();
+    /// let np = PyArrayModule::import(gil.python()).unwrap();
+    /// let arr = PyArray::<i64, _>::from_vec(gil.python(), &np, vec![1, 2, 3]);
+    /// let broadcasted = arr.broadcast_to(gil.python(), &np, &[2, 3]).unwrap();
+    /// assert_eq!(broadcasted.shape(), &[2, 3]);
+    /// assert_eq!(broadcasted.strides()[0], 0); // the new axis repeats, rather than advancing
+    /// assert!(arr.broadcast_to(gil.python(), &np, &[4]).is_err());
+    /// # }
+    /// ```
+    pub fn broadcast_to(
+        &self,
+        py: Python,
+        np: &PyArrayModule,
+        shape: &[usize],
+    ) -> Result<PyArrayDyn<T>, BroadcastError> {
+        let out_shape = broadcast_shape(&[self.shape(), shape])?;
+        if out_shape != shape {
+            return Err(BroadcastError::incompatible(self.shape(), shape));
+        }
+        let out_strides = broadcast_strides(self.shape(), self.strides(), &out_shape)?;
+        unsafe {
+            let mut np_strides: Vec<npyffi::npy_intp> =
+                out_strides.iter().map(|&s| s as npyffi::npy_intp).collect();
+            let view = PyArray::<T, IxDyn>::new_(
+                py,
+                np,
+                &out_shape,
+                np_strides.as_mut_ptr(),
+                self.data() as *mut c_void,
+            );
+            let base = self.clone_ref(py);
+            np.PyArray_SetBaseObject(view.as_array_ptr(), base.into_ptr());
+            Ok(view)
+        }
+    }
+}
+
+/// Broadcast every array in `arrays` against their combined shape, mirroring
+/// `numpy.broadcast_arrays`.
+pub fn broadcast_arrays<T: TypeNum, D>(
+    py: Python,
+    np: &PyArrayModule,
+    arrays: &[&PyArray<T, D>],
+) -> Result<Vec<PyArrayDyn<T>>, BroadcastError> {
+    let shapes: Vec<&[usize]> = arrays.iter().map(|a| a.shape()).collect();
+    let out_shape = broadcast_shape(&shapes)?;
+    arrays
+        .iter()
+        .map(|a| a.broadcast_to(py, np, &out_shape))
+        .collect()
+}
@@ -0,0 +1,43 @@
+//! A Python object that owns a Rust-allocated buffer.
+//!
+//! The owning `PyArray` constructors (`from_vec`, `from_boxed_slice`,
+//! `from_vec2`, `from_vec3`, and `from_ndarray`) hand a raw pointer straight
+//! to `PyArray_New`, which leaves NumPy unaware of
+//! how the memory was allocated. Wrapping the original `Vec`/`Box<[T]>` in a
+//! `PySliceContainer` and setting it as the array's `base` object means that
+//! when NumPy garbage-collects the array, the container's `Drop` runs and
+//! frees the buffer with Rust's allocator instead of leaking it (or worse,
+//! handing it to `free()` if NumPy ever reallocates in place).
+
+use pyo3::prelude::*;
+
+use super::npyffi;
+use super::PyArrayModule;
+
+/// Owns a boxed slice for as long as the Python object wrapping it is alive.
+#[pyclass]
+pub struct PySliceContainer {
+    data: Box<dyn std::any::Any + Send>,
+}
+
+impl PySliceContainer {
+    pub fn from_boxed_slice<T: 'static + Send>(data: Box<[T]>) -> Self {
+        PySliceContainer { data: Box::new(data) }
+    }
+
+    pub fn from_vec<T: 'static + Send>(data: Vec<T>) -> Self {
+        Self::from_boxed_slice(data.into_boxed_slice())
+    }
+}
+
+/// Set `container`'s boxed data as `array`'s `base` object, transferring
+/// ownership of the buffer to Python's garbage collector.
+pub(crate) unsafe fn set_base_object(
+    py: Python,
+    array: *mut npyffi::PyArrayObject,
+    np: &PyArrayModule,
+    container: PySliceContainer,
+) {
+    let obj = Py::new(py, container).expect("allocating PySliceContainer failed");
+    np.PyArray_SetBaseObject(array, obj.into_object(py).into_ptr());
+}
@@ -0,0 +1,79 @@
+//! Build a `PyArray` from a borrowed `ndarray` view, without requiring the
+//! caller to give up ownership the way [`IntoPyArray`](../convert/trait.IntoPyArray.html) does.
+
+use ndarray::{Array, ArrayView, Dimension};
+use pyo3::Python;
+
+use super::array::PyArray;
+use super::module::PyArrayModule;
+use super::types::TypeNum;
+
+/// Copy `self`'s data into a new NumPy array, borrowing rather than
+/// consuming the source.
+///
+/// If the source is in standard (C-contiguous) layout, this is a single `memcpy` into a freshly
+/// allocated NumPy buffer (preserving shape and strides); otherwise the
+/// elements are copied one at a time, honoring the source's strides.
+///
+/// # Example
+/// ```
+/// # extern crate pyo3; extern crate numpy; #[macro_use] extern crate ndarray; fn main() {
+/// use numpy::{PyArrayModule, ToPyArray};
+/// let gil = pyo3::Python::acquire_gil();
+/// let np = PyArrayModule::import(gil.python()).unwrap();
+/// // the standard-layout source is copied with a single memcpy
+/// let standard = array![[0, 1], [2, 3]];
+/// let pyarray = standard.to_pyarray(gil.python(), &np);
+/// assert_eq!(pyarray.as_array().unwrap(), standard);
+/// // a transposed view is not in standard layout, so each element is
+/// // copied individually, honoring the view's strides
+/// let transposed = standard.view().reversed_axes();
+/// let pyarray = transposed.to_pyarray(gil.python(), &np);
+/// assert_eq!(pyarray.as_array().unwrap(), transposed);
+/// # }
+/// ```
+pub trait ToPyArray {
+    type Item: TypeNum;
+    type Dim: Dimension;
+    fn to_pyarray(&self, py: Python, np: &PyArrayModule) -> PyArray<Self::Item, Self::Dim>;
+}
+
+// `Array` and `ArrayView` are distinct concrete types (`ArrayBase<OwnedRepr<A>, D>`
+// vs. `ArrayBase<ViewRepr<&A>, D>`), so implementing `ToPyArray` for each
+// separately - rather than blanket over `ArrayBase<S, D>` - avoids a
+// conflicting-impls error between them.
+impl<A: TypeNum, D: Dimension> ToPyArray for Array<A, D> {
+    type Item = A;
+    type Dim = D;
+    fn to_pyarray(&self, py: Python, np: &PyArrayModule) -> PyArray<A, D> {
+        self.view().to_pyarray(py, np)
+    }
+}
+
+impl<'a, A: TypeNum, D: Dimension> ToPyArray for ArrayView<'a, A, D> {
+    type Item = A;
+    type Dim = D;
+    fn to_pyarray(&self, py: Python, np: &PyArrayModule) -> PyArray<A, D> {
+        let dims: Vec<usize> = self.shape().to_vec();
+        let array = PyArray::<A, D>::new(py, np, &dims);
+        let mut dst = array
+            .as_array_mut()
+            .expect("freshly allocated array always matches its own dtype");
+        if self.is_standard_layout() {
+            dst.as_slice_mut()
+                .expect("freshly allocated array is always contiguous")
+                .clone_from_slice(self.as_slice().expect("checked standard layout above"));
+        } else {
+            dst.zip_mut_with(self, |dst, src| *dst = src.clone());
+        }
+        array
+    }
+}
+
+impl<A: TypeNum> ToPyArray for [A] {
+    type Item = A;
+    type Dim = ndarray::Ix1;
+    fn to_pyarray(&self, py: Python, np: &PyArrayModule) -> PyArray<A, ndarray::Ix1> {
+        ArrayView::from(self).to_pyarray(py, np)
+    }
+}